@@ -3,8 +3,32 @@ use libc::*;
 use std::convert::Into;
 use std::ffi::CString;
 
+/// Reclaims a `CString` previously leaked via `CString::into_raw`, unless
+/// `ptr` is null (the sentinel used throughout this module for an absent
+/// `Option<String>` field). `CString::from_raw` on a null pointer is UB, so
+/// every optional string field must be freed through this instead of
+/// calling it directly.
+unsafe fn free_optional_cstring(ptr: *const c_char) {
+    if !ptr.is_null() {
+        let _ = CString::from_raw(ptr as *mut c_char);
+    }
+}
+
+/// Reclaims a `Vec<T>` previously leaked via `Vec::into_raw_parts`, unless
+/// `ptr` is null (the sentinel this module uses for an absent array field).
+/// `Vec::from_raw_parts` requires a non-null pointer even for an empty
+/// vec, so every optional array field must be freed through this instead of
+/// calling it directly.
+unsafe fn free_optional_vec<T>(ptr: *const T, len: usize, cap: usize) -> Vec<T> {
+    if ptr.is_null() {
+        Vec::new()
+    } else {
+        Vec::from_raw_parts(ptr as *mut T, len, cap)
+    }
+}
+
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum BundelPolicy {
     Balanced = 1,
     MaxCompat,
@@ -12,7 +36,7 @@ pub enum BundelPolicy {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum IceTransportPolicy {
     None = 1,
     Relay,
@@ -21,7 +45,7 @@ pub enum IceTransportPolicy {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum RtcpMuxPolicy {
     Negotiate = 1,
     Require,
@@ -39,10 +63,10 @@ pub struct RawRTCIceServer {
 impl Drop for RawRTCIceServer {
     fn drop(&mut self) {
         unsafe {
-            let _ = CString::from_raw(self.credential as *mut c_char);
-            let _ = CString::from_raw(self.username as *mut c_char);
-            for url in Vec::from_raw_parts(
-                self.urls as *mut *const c_char,
+            free_optional_cstring(self.credential);
+            free_optional_cstring(self.username);
+            for url in free_optional_vec(
+                self.urls,
                 self.urls_size as usize,
                 self.urls_capacity as usize,
             ) {
@@ -62,17 +86,33 @@ pub struct RawRTCPeerConnectionConfigure {
     ice_servers_size: u8,
     ice_servers_capacity: u8,
     ice_candidate_pool_size: u8,
+    certificates: *const RawRTCCertificate,
+    certificates_size: u8,
+    certificates_capacity: u8,
+    bind_address: *const c_char,
+    port_range_begin: u16,
+    port_range_end: u16,
+    enable_ice_tcp: bool,
+    mtu: i32,
+    max_message_size: i32,
 }
 
 impl Drop for RawRTCPeerConnectionConfigure {
     fn drop(&mut self) {
         unsafe {
-            let _ = CString::from_raw(self.peer_identity as *mut c_char);
-            let _ = Vec::from_raw_parts(
-                self.ice_servers as *mut RawRTCIceServer,
+            free_optional_cstring(self.peer_identity);
+            free_optional_cstring(self.bind_address);
+            let _ = free_optional_vec(
+                self.ice_servers,
                 self.ice_servers_size as usize,
                 self.ice_servers_capacity as usize,
             );
+
+            let _ = free_optional_vec(
+                self.certificates,
+                self.certificates_size as usize,
+                self.certificates_capacity as usize,
+            );
         }
     }
 }
@@ -106,8 +146,13 @@ pub struct RTCIceServer {
     pub urls: Option<Vec<String>>,
 }
 
-impl Into<RawRTCIceServer> for RTCIceServer {
-    fn into(self) -> RawRTCIceServer {
+impl RTCIceServer {
+    /// Marshals this `RTCIceServer` to its raw FFI representation, assuming
+    /// it has already passed `validate_ice_server` (interior NUL bytes would
+    /// otherwise panic in `CString::new`). Kept `pub(crate)` and reachable
+    /// only through `RTCConfiguration::try_into_raw`/`RTCConfigurationBuilder::try_into_raw`
+    /// so the panicking path can't be hit directly from outside the crate.
+    pub(crate) fn into_raw_unchecked(self) -> RawRTCIceServer {
         let (urls, urls_size, urls_capacity) = self
             .urls
             .map(|v| {
@@ -134,6 +179,141 @@ impl Into<RawRTCIceServer> for RTCIceServer {
     }
 }
 
+/// RTCCertificate
+///
+/// The algorithm used to generate an RTCCertificate's key pair. This pins the
+/// DTLS identity used to secure a connection, and offering multiple
+/// algorithms across a set of certificates can improve interop odds with
+/// remote peers that only support a subset of them.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CertificateAlgorithm {
+    EcdsaP256 = 1,
+    Rsa2048,
+}
+
+#[repr(C)]
+pub struct RawRTCCertificate {
+    key: *const c_char,
+    cert: *const c_char,
+    algorithm: u8, // CertificateAlgorithm
+}
+
+impl Drop for RawRTCCertificate {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CString::from_raw(self.key as *mut c_char);
+            let _ = CString::from_raw(self.cert as *mut c_char);
+        }
+    }
+}
+
+impl RTCCertificate {
+    /// Marshals this `RTCCertificate` to its raw FFI representation,
+    /// assuming it has already passed `validate_certificate` (interior NUL
+    /// bytes would otherwise panic in `CString::new`). Kept `pub(crate)` for
+    /// the same reason as `RTCIceServer::into_raw_unchecked`.
+    pub(crate) fn into_raw_unchecked(self) -> RawRTCCertificate {
+        RawRTCCertificate {
+            key: CString::new(self.key).unwrap().into_raw(),
+            cert: CString::new(self.cert).unwrap().into_raw(),
+            algorithm: self.algorithm as u8,
+        }
+    }
+}
+
+/// A certificate pinning the DTLS identity used by an RTCPeerConnection,
+/// holding a PEM-encoded private key and certificate together with the
+/// algorithm they were generated with.
+///
+/// Passing one or more certificates lets an application reuse a stable DTLS
+/// identity across reconnects, rather than having a fresh ephemeral
+/// certificate generated for every RTCPeerConnection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RTCCertificate {
+    /// The PEM-encoded private key.
+    pub key: String,
+    /// The PEM-encoded certificate.
+    pub cert: String,
+    /// The algorithm this certificate's key pair was generated with.
+    pub algorithm: CertificateAlgorithm,
+}
+
+#[cfg(not(test))]
+extern "C" {
+    fn rtc_generate_certificate(algorithm: u8) -> *const RawRTCCertificate;
+    fn rtc_free_certificate(certificate: *const RawRTCCertificate);
+}
+
+// The native library isn't linked into test builds, so route the same calls
+// through an in-process stub that hands back a struct allocated the same
+// way the real native side would have to free it correctly.
+#[cfg(test)]
+use certificate_native_stub::{rtc_free_certificate, rtc_generate_certificate};
+
+impl RTCCertificate {
+    /// Generates a fresh self-signed certificate for the given algorithm, so
+    /// callers can hold onto the result and reuse it across reconnects
+    /// instead of letting a new identity be generated implicitly.
+    ///
+    /// Generation happens on the native side (the same WebRTC DTLS identity
+    /// machinery `PeerConnectionFactory::CreateCertificate` uses), so this
+    /// crate doesn't need to pull in its own crypto/certificate dependency.
+    /// The returned struct is allocated by that native side, not by Rust's
+    /// global allocator, so its `key`/`cert` strings are only ever copied
+    /// out with `CStr::from_ptr(...).to_string_lossy()` and the struct
+    /// itself is released through `rtc_free_certificate`, never through
+    /// `Box::from_raw`/`CString::from_raw`.
+    pub fn generate(algorithm: CertificateAlgorithm) -> Result<Self> {
+        let raw = unsafe { rtc_generate_certificate(algorithm as u8) };
+        if raw.is_null() {
+            return Err(anyhow::anyhow!(
+                "failed to generate a self-signed certificate"
+            ));
+        }
+
+        let certificate = unsafe {
+            Self {
+                key: std::ffi::CStr::from_ptr((*raw).key)
+                    .to_string_lossy()
+                    .into_owned(),
+                cert: std::ffi::CStr::from_ptr((*raw).cert)
+                    .to_string_lossy()
+                    .into_owned(),
+                algorithm,
+            }
+        };
+
+        unsafe {
+            rtc_free_certificate(raw);
+        }
+
+        Ok(certificate)
+    }
+}
+
+#[cfg(test)]
+mod certificate_native_stub {
+    use super::RawRTCCertificate;
+    use std::ffi::CString;
+
+    pub unsafe fn rtc_generate_certificate(_algorithm: u8) -> *const RawRTCCertificate {
+        Box::into_raw(Box::new(RawRTCCertificate {
+            key: CString::new("test-key").unwrap().into_raw(),
+            cert: CString::new("test-cert").unwrap().into_raw(),
+            algorithm: 0,
+        }))
+    }
+
+    /// This stub happens to have allocated its fake certificate the same
+    /// way `Drop for RawRTCCertificate` expects, so reclaiming it here is
+    /// safe even though the real native side never goes through Rust's
+    /// allocator for a `generate()`-returned certificate.
+    pub unsafe fn rtc_free_certificate(certificate: *const RawRTCCertificate) {
+        drop(Box::from_raw(certificate as *mut RawRTCCertificate));
+    }
+}
+
 /// RTCPeerConnection
 ///
 /// The RTCPeerConnection is a newly-created RTCPeerConnection,
@@ -178,20 +358,64 @@ pub struct RTCConfiguration {
     /// before you start trying to connect, so that they're already available
     /// for inspection when RTCPeerConnection.setLocalDescription() is called.
     pub ice_candidate_pool_size: Option<u8>,
+    /// Certificates pinning the DTLS identity of the connection. If this
+    /// isn't specified, a fresh, self-signed certificate is generated for
+    /// each RTCPeerConnection. Although only one certificate is used by a
+    /// given DTLS connection, offering more than one allows the caller to
+    /// cover multiple algorithms, which improves the odds of interoperating
+    /// with a remote peer.
+    pub certificates: Option<Vec<RTCCertificate>>,
+    /// Binds ICE candidate gathering to a single local address, useful for
+    /// pinning media to a specific NIC on a multi-homed host. If this isn't
+    /// specified, candidates are gathered on every local address.
+    pub bind_address: Option<String>,
+    /// The lower bound, inclusive, of the UDP/TCP port range used for ICE
+    /// candidates. Must be combined with `port_range_end`; together they
+    /// let a deployment behind a restrictive firewall open only a known
+    /// range of ports.
+    pub port_range_begin: Option<u16>,
+    /// The upper bound, inclusive, of the UDP/TCP port range used for ICE
+    /// candidates.
+    pub port_range_end: Option<u16>,
+    /// Enables ICE-TCP (RFC 6544) candidates in addition to UDP, allowing
+    /// connectivity to be established where UDP is blocked.
+    pub enable_ice_tcp: bool,
+    /// Caps the maximum transmission unit, in bytes, used for outgoing
+    /// packets.
+    pub mtu: Option<i32>,
+    /// Caps the maximum size, in bytes, of a single SCTP message sent over
+    /// a data channel.
+    pub max_message_size: Option<i32>,
 }
 
-impl Into<RawRTCPeerConnectionConfigure> for RTCConfiguration {
-    fn into(self) -> RawRTCPeerConnectionConfigure {
+impl RTCConfiguration {
+    /// Marshals this `RTCConfiguration` to its raw FFI representation,
+    /// assuming it has already passed `RTCConfiguration::validate` (interior
+    /// NUL bytes would otherwise panic, and an inverted port range is passed
+    /// through as is). Kept `pub(crate)` and reachable only through
+    /// `try_into_raw`/`RTCConfigurationBuilder::try_into_raw` so the panicking path can't be hit
+    /// directly from outside the crate.
+    pub(crate) fn into_raw_unchecked(self) -> RawRTCPeerConnectionConfigure {
         let (ice_servers, ice_servers_size, ice_servers_capacity) = self
             .ice_servers
             .map(|i| {
-                i.iter()
-                    .map(|s| s.clone().into())
+                i.into_iter()
+                    .map(RTCIceServer::into_raw_unchecked)
                     .collect::<Vec<RawRTCIceServer>>()
                     .into_raw_parts()
             })
             .unwrap_or((std::ptr::null_mut(), 0, 0));
-        
+
+        let (certificates, certificates_size, certificates_capacity) = self
+            .certificates
+            .map(|i| {
+                i.into_iter()
+                    .map(RTCCertificate::into_raw_unchecked)
+                    .collect::<Vec<RawRTCCertificate>>()
+                    .into_raw_parts()
+            })
+            .unwrap_or((std::ptr::null_mut(), 0, 0));
+
         RawRTCPeerConnectionConfigure {
             bundle_policy: self.bundle_policy.map(|i| i as u8).unwrap_or(0),
             ice_transport_policy: self.ice_transport_policy.map(|i| i as u8).unwrap_or(0),
@@ -204,6 +428,528 @@ impl Into<RawRTCPeerConnectionConfigure> for RTCConfiguration {
             ice_servers_capacity: ice_servers_capacity as u8,
             ice_servers_size: ice_servers_size as u8,
             ice_servers,
+            certificates_capacity: certificates_capacity as u8,
+            certificates_size: certificates_size as u8,
+            certificates,
+            bind_address: self
+                .bind_address
+                .map(|s| CString::new(s).unwrap().into_raw())
+                .unwrap_or(std::ptr::null_mut()),
+            port_range_begin: self.port_range_begin.unwrap_or(0),
+            port_range_end: self.port_range_end.unwrap_or(0),
+            enable_ice_tcp: self.enable_ice_tcp,
+            mtu: self.mtu.unwrap_or(-1),
+            max_message_size: self.max_message_size.unwrap_or(-1),
+        }
+    }
+}
+
+/// An error returned when an `RTCConfiguration` cannot be applied.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// `setConfiguration` was called with a change to a field the spec
+    /// requires to stay fixed for the lifetime of the RTCPeerConnection.
+    ImmutableField(&'static str),
+    /// A string field contained an interior NUL byte, which cannot be
+    /// represented as a C string.
+    NulByte(&'static str),
+    /// An ICE server URL did not use the `stun:`, `stuns:`, `turn:` or
+    /// `turns:` scheme.
+    InvalidUrlScheme(String),
+    /// An ICE server had a `turn:`/`turns:` URL but no `username` and
+    /// `credential` to authenticate with.
+    MissingTurnCredentials,
+    /// `port_range_begin` was greater than `port_range_end`.
+    InvalidPortRange,
+    /// `ice_candidate_pool_size` exceeded `MAX_ICE_CANDIDATE_POOL_SIZE`.
+    IceCandidatePoolSizeTooLarge(u8),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ImmutableField(field) => {
+                write!(f, "{} cannot be changed after construction", field)
+            }
+            Self::NulByte(field) => write!(f, "{} contains an interior NUL byte", field),
+            Self::InvalidUrlScheme(url) => write!(
+                f,
+                "'{}' does not use a stun:, stuns:, turn: or turns: scheme",
+                url
+            ),
+            Self::MissingTurnCredentials => {
+                write!(
+                    f,
+                    "a TURN/TURNS ice server requires a username and credential"
+                )
+            }
+            Self::InvalidPortRange => {
+                write!(
+                    f,
+                    "port_range_begin must be less than or equal to port_range_end"
+                )
+            }
+            Self::IceCandidatePoolSizeTooLarge(size) => write!(
+                f,
+                "ice_candidate_pool_size of {} exceeds the maximum of {}",
+                size, MAX_ICE_CANDIDATE_POOL_SIZE
+            ),
         }
     }
-}
\ No newline at end of file
+}
+
+impl std::error::Error for ConfigError {}
+
+/// The maximum `ice_candidate_pool_size` accepted by `RTCConfigurationBuilder`.
+/// Prefetching more candidates than this rarely helps connection time and
+/// only adds gathering overhead.
+pub const MAX_ICE_CANDIDATE_POOL_SIZE: u8 = 100;
+
+fn validate_no_nul(value: &str, field: &'static str) -> Result<(), ConfigError> {
+    if value.contains('\0') {
+        Err(ConfigError::NulByte(field))
+    } else {
+        Ok(())
+    }
+}
+
+fn is_turn_url(url: &str) -> bool {
+    url.starts_with("turn:") || url.starts_with("turns:")
+}
+
+fn validate_url_scheme(url: &str) -> Result<(), ConfigError> {
+    let valid = ["stun:", "stuns:", "turn:", "turns:"]
+        .iter()
+        .any(|scheme| url.starts_with(scheme));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(ConfigError::InvalidUrlScheme(url.to_string()))
+    }
+}
+
+fn validate_ice_server(server: &RTCIceServer) -> Result<(), ConfigError> {
+    let urls = server.urls.iter().flatten();
+    for url in urls.clone() {
+        validate_no_nul(url, "ice_server.urls")?;
+        validate_url_scheme(url)?;
+    }
+
+    if let Some(credential) = &server.credential {
+        validate_no_nul(credential, "ice_server.credential")?;
+    }
+
+    if let Some(username) = &server.username {
+        validate_no_nul(username, "ice_server.username")?;
+    }
+
+    if urls.clone().any(|url| is_turn_url(url))
+        && (server.username.is_none() || server.credential.is_none())
+    {
+        return Err(ConfigError::MissingTurnCredentials);
+    }
+
+    Ok(())
+}
+
+fn validate_certificate(certificate: &RTCCertificate) -> Result<(), ConfigError> {
+    validate_no_nul(&certificate.key, "certificate.key")?;
+    validate_no_nul(&certificate.cert, "certificate.cert")?;
+    Ok(())
+}
+
+/// A validating builder for `RTCIceServer`.
+///
+/// Unlike constructing an `RTCIceServer` directly, `build()` rejects interior NUL bytes, URLs that
+/// don't use a `stun:`/`stuns:`/`turn:`/`turns:` scheme, and TURN/TURNS
+/// servers missing a `username`/`credential` pair, instead of panicking at
+/// the FFI boundary.
+#[derive(Default, Clone, Debug)]
+pub struct RTCIceServerBuilder {
+    urls: Vec<String>,
+    username: Option<String>,
+    credential: Option<String>,
+}
+
+impl RTCIceServerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.urls.push(url.into());
+        self
+    }
+
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    pub fn credential(mut self, credential: impl Into<String>) -> Self {
+        self.credential = Some(credential.into());
+        self
+    }
+
+    pub fn build(self) -> Result<RTCIceServer, ConfigError> {
+        let server = RTCIceServer {
+            urls: if self.urls.is_empty() {
+                None
+            } else {
+                Some(self.urls)
+            },
+            username: self.username,
+            credential: self.credential,
+        };
+
+        validate_ice_server(&server)?;
+        Ok(server)
+    }
+}
+
+impl RTCConfiguration {
+    /// Checks `next` against `self` for compliance with the spec's
+    /// `setConfiguration` rules, rejecting changes to fields that are fixed
+    /// for the lifetime of the RTCPeerConnection (`bundle_policy`,
+    /// `rtcp_mux_policy`, `ice_candidate_pool_size`, `certificates`,
+    /// `peer_identity`, and the
+    /// gathering/socket-time transport tuning fields `bind_address`,
+    /// `port_range_begin`/`port_range_end`, `enable_ice_tcp`, `mtu`, and
+    /// `max_message_size`, none of which have a `SetConfiguration`
+    /// equivalent on the native side), while allowing `ice_servers` and
+    /// `ice_transport_policy` to change.
+    pub fn validate_mutation(&self, next: &Self) -> Result<(), ConfigError> {
+        if self.bundle_policy != next.bundle_policy {
+            return Err(ConfigError::ImmutableField("bundle_policy"));
+        }
+
+        if self.rtcp_mux_policy != next.rtcp_mux_policy {
+            return Err(ConfigError::ImmutableField("rtcp_mux_policy"));
+        }
+
+        if self.ice_candidate_pool_size != next.ice_candidate_pool_size {
+            return Err(ConfigError::ImmutableField("ice_candidate_pool_size"));
+        }
+
+        if self.certificates != next.certificates {
+            return Err(ConfigError::ImmutableField("certificates"));
+        }
+
+        if self.peer_identity != next.peer_identity {
+            return Err(ConfigError::ImmutableField("peer_identity"));
+        }
+
+        if self.bind_address != next.bind_address {
+            return Err(ConfigError::ImmutableField("bind_address"));
+        }
+
+        if self.port_range_begin != next.port_range_begin {
+            return Err(ConfigError::ImmutableField("port_range_begin"));
+        }
+
+        if self.port_range_end != next.port_range_end {
+            return Err(ConfigError::ImmutableField("port_range_end"));
+        }
+
+        if self.enable_ice_tcp != next.enable_ice_tcp {
+            return Err(ConfigError::ImmutableField("enable_ice_tcp"));
+        }
+
+        if self.mtu != next.mtu {
+            return Err(ConfigError::ImmutableField("mtu"));
+        }
+
+        if self.max_message_size != next.max_message_size {
+            return Err(ConfigError::ImmutableField("max_message_size"));
+        }
+
+        Ok(())
+    }
+
+    /// Runs every `ConfigError` check on this configuration: interior NUL
+    /// bytes in `peer_identity`/`bind_address`, an `ice_candidate_pool_size`
+    /// within `MAX_ICE_CANDIDATE_POOL_SIZE`, `port_range_begin <=
+    /// port_range_end`, and every `ice_servers`/`certificates` entry.
+    /// Shared by `try_into_raw` and `RTCConfigurationBuilder::build` so the
+    /// two can't drift apart.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(peer_identity) = &self.peer_identity {
+            validate_no_nul(peer_identity, "peer_identity")?;
+        }
+
+        if let Some(bind_address) = &self.bind_address {
+            validate_no_nul(bind_address, "bind_address")?;
+        }
+
+        if let Some(pool_size) = self.ice_candidate_pool_size {
+            if pool_size > MAX_ICE_CANDIDATE_POOL_SIZE {
+                return Err(ConfigError::IceCandidatePoolSizeTooLarge(pool_size));
+            }
+        }
+
+        if let (Some(begin), Some(end)) = (self.port_range_begin, self.port_range_end) {
+            if begin > end {
+                return Err(ConfigError::InvalidPortRange);
+            }
+        }
+
+        for server in self.ice_servers.iter().flatten() {
+            validate_ice_server(server)?;
+        }
+
+        for certificate in self.certificates.iter().flatten() {
+            validate_certificate(certificate)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates this configuration and marshals it to its raw FFI
+    /// representation, without panicking on malformed input.
+    pub fn try_into_raw(self) -> Result<RawRTCPeerConnectionConfigure, ConfigError> {
+        self.validate()?;
+        Ok(self.into_raw_unchecked())
+    }
+}
+
+/// A validating builder for `RTCConfiguration`.
+///
+/// `build()` runs `RTCConfiguration::validate` (interior NUL bytes, ICE
+/// server URL schemes and TURN credentials, a bounded
+/// `ice_candidate_pool_size`, and a sane port range) without requiring a
+/// native FFI round trip, so callers can validate a configuration up front
+/// and still call `try_into_raw` later.
+#[derive(Default, Clone, Debug)]
+pub struct RTCConfigurationBuilder {
+    configuration: RTCConfiguration,
+}
+
+impl RTCConfigurationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn bundle_policy(mut self, policy: BundelPolicy) -> Self {
+        self.configuration.bundle_policy = Some(policy);
+        self
+    }
+
+    pub fn ice_transport_policy(mut self, policy: IceTransportPolicy) -> Self {
+        self.configuration.ice_transport_policy = Some(policy);
+        self
+    }
+
+    pub fn peer_identity(mut self, peer_identity: impl Into<String>) -> Self {
+        self.configuration.peer_identity = Some(peer_identity.into());
+        self
+    }
+
+    pub fn rtcp_mux_policy(mut self, policy: RtcpMuxPolicy) -> Self {
+        self.configuration.rtcp_mux_policy = Some(policy);
+        self
+    }
+
+    pub fn ice_server(mut self, server: RTCIceServer) -> Self {
+        self.configuration
+            .ice_servers
+            .get_or_insert_with(Vec::new)
+            .push(server);
+        self
+    }
+
+    pub fn ice_candidate_pool_size(mut self, size: u8) -> Self {
+        self.configuration.ice_candidate_pool_size = Some(size);
+        self
+    }
+
+    pub fn certificate(mut self, certificate: RTCCertificate) -> Self {
+        self.configuration
+            .certificates
+            .get_or_insert_with(Vec::new)
+            .push(certificate);
+        self
+    }
+
+    pub fn bind_address(mut self, bind_address: impl Into<String>) -> Self {
+        self.configuration.bind_address = Some(bind_address.into());
+        self
+    }
+
+    pub fn port_range(mut self, begin: u16, end: u16) -> Self {
+        self.configuration.port_range_begin = Some(begin);
+        self.configuration.port_range_end = Some(end);
+        self
+    }
+
+    pub fn enable_ice_tcp(mut self, enable: bool) -> Self {
+        self.configuration.enable_ice_tcp = enable;
+        self
+    }
+
+    pub fn mtu(mut self, mtu: i32) -> Self {
+        self.configuration.mtu = Some(mtu);
+        self
+    }
+
+    pub fn max_message_size(mut self, max_message_size: i32) -> Self {
+        self.configuration.max_message_size = Some(max_message_size);
+        self
+    }
+
+    pub fn build(self) -> Result<RTCConfiguration, ConfigError> {
+        let configuration = self.configuration;
+        configuration.validate()?;
+        Ok(configuration)
+    }
+
+    /// Validates and marshals directly to the raw FFI representation.
+    pub fn try_into_raw(self) -> Result<RawRTCPeerConnectionConfigure, ConfigError> {
+        self.build()?.try_into_raw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_nul_byte_in_peer_identity() {
+        let err = RTCConfigurationBuilder::new()
+            .peer_identity("bad\0identity")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::NulByte("peer_identity")));
+    }
+
+    #[test]
+    fn rejects_nul_byte_in_bind_address() {
+        let err = RTCConfigurationBuilder::new()
+            .bind_address("127.0.0.1\0")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::NulByte("bind_address")));
+    }
+
+    #[test]
+    fn rejects_invalid_ice_server_url_scheme() {
+        let err = RTCIceServerBuilder::new()
+            .url("https://example.com")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidUrlScheme(_)));
+    }
+
+    #[test]
+    fn rejects_nul_byte_in_ice_server_url() {
+        let err = RTCIceServerBuilder::new()
+            .url("stun:example.com\0")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::NulByte("ice_server.urls")));
+    }
+
+    #[test]
+    fn rejects_turn_server_missing_credentials() {
+        let err = RTCIceServerBuilder::new()
+            .url("turn:turn.example.com")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::MissingTurnCredentials));
+    }
+
+    #[test]
+    fn rejects_turn_server_missing_credential_only() {
+        let err = RTCIceServerBuilder::new()
+            .url("turn:turn.example.com")
+            .username("user")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::MissingTurnCredentials));
+    }
+
+    #[test]
+    fn accepts_stun_server_without_credentials() {
+        RTCIceServerBuilder::new()
+            .url("stun:stun.example.com")
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn rejects_excessive_ice_candidate_pool_size() {
+        let err = RTCConfigurationBuilder::new()
+            .ice_candidate_pool_size(MAX_ICE_CANDIDATE_POOL_SIZE + 1)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::IceCandidatePoolSizeTooLarge(_)));
+    }
+
+    #[test]
+    fn rejects_inverted_port_range() {
+        let err = RTCConfigurationBuilder::new()
+            .port_range(5000, 4000)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidPortRange));
+    }
+
+    #[test]
+    fn rejects_nul_byte_in_certificate() {
+        let certificate = RTCCertificate {
+            key: "bad\0key".to_string(),
+            cert: "cert".to_string(),
+            algorithm: CertificateAlgorithm::EcdsaP256,
+        };
+
+        let err = RTCConfigurationBuilder::new()
+            .certificate(certificate)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::NulByte("certificate.key")));
+    }
+
+    #[test]
+    fn try_into_raw_shares_validation_with_build() {
+        let configuration = RTCConfiguration {
+            port_range_begin: Some(5000),
+            port_range_end: Some(4000),
+            ..Default::default()
+        };
+
+        match configuration.try_into_raw() {
+            Err(ConfigError::InvalidPortRange) => {}
+            other => panic!("expected InvalidPortRange, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn validate_mutation_rejects_bundle_policy_change() {
+        let original = RTCConfiguration::default();
+        let next = RTCConfiguration {
+            bundle_policy: Some(BundelPolicy::MaxBundle),
+            ..Default::default()
+        };
+
+        let err = original.validate_mutation(&next).unwrap_err();
+        assert!(matches!(err, ConfigError::ImmutableField("bundle_policy")));
+    }
+
+    #[test]
+    fn validate_mutation_allows_ice_transport_policy_change() {
+        let original = RTCConfiguration::default();
+        let next = RTCConfiguration {
+            ice_transport_policy: Some(IceTransportPolicy::Relay),
+            ..Default::default()
+        };
+
+        original.validate_mutation(&next).unwrap();
+    }
+
+    #[test]
+    fn generate_copies_native_strings_and_frees_native_struct() {
+        let certificate = RTCCertificate::generate(CertificateAlgorithm::EcdsaP256).unwrap();
+        assert_eq!(certificate.key, "test-key");
+        assert_eq!(certificate.cert, "test-cert");
+    }
+}
@@ -0,0 +1,193 @@
+use crate::ffi::raw::rtc_peerconnection_configure::{
+    RTCConfiguration, RawRTCPeerConnectionConfigure,
+};
+use anyhow::Result;
+use libc::*;
+
+#[cfg(not(test))]
+extern "C" {
+    fn rtc_set_configuration(pc: *const c_void, config: *const RawRTCPeerConnectionConfigure);
+    fn rtc_restart_ice(pc: *const c_void);
+    fn rtc_create_offer(pc: *const c_void, ice_restart: bool) -> *const c_char;
+    fn rtc_free_string(s: *const c_char);
+}
+
+// The native library isn't linked into test builds, so route the same calls
+// through an in-process stub that records what it was asked to do instead.
+#[cfg(test)]
+use native_stub::{rtc_create_offer, rtc_free_string, rtc_restart_ice, rtc_set_configuration};
+
+/// RTCOfferOptions
+///
+/// Options that customize the offer created by `RTCPeerConnection::create_offer`.
+#[derive(Default, Clone, Copy, Debug)]
+pub struct RTCOfferOptions {
+    /// When `true`, the generated offer forces ICE to generate new local
+    /// ICE credentials (ufrag and password) and restart candidate
+    /// gathering, as if the ICE agent had just been created. Combine this
+    /// with `RTCPeerConnection::set_configuration` to fail over to a new
+    /// set of ICE servers, e.g. switching `ice_transport_policy` to
+    /// `Relay` and restarting ICE to force relay-only connectivity.
+    pub ice_restart: bool,
+}
+
+/// RTCPeerConnection
+///
+/// The RTCPeerConnection is a newly-created RTCPeerConnection, which
+/// represents a connection between the local device and a remote peer.
+pub struct RTCPeerConnection {
+    raw: *const c_void,
+    configuration: RTCConfiguration,
+}
+
+impl RTCPeerConnection {
+    /// Updates the configuration of this RTCPeerConnection following
+    /// `PeerConnectionInterface::SetConfiguration`.
+    ///
+    /// Most fields of an `RTCConfiguration` are fixed for the lifetime of
+    /// the RTCPeerConnection; only `ice_servers` and `ice_transport_policy`
+    /// may change after construction. Any other change is rejected with a
+    /// `ConfigError` before it ever reaches the native layer, so callers
+    /// can rotate TURN credentials without tearing down media.
+    pub fn set_configuration(&mut self, configuration: RTCConfiguration) -> Result<()> {
+        self.configuration.validate_mutation(&configuration)?;
+
+        let raw = configuration.clone().try_into_raw()?.into_raw();
+        unsafe {
+            rtc_set_configuration(self.raw, raw);
+        }
+
+        RawRTCPeerConnectionConfigure::from_raw(raw);
+        self.configuration = configuration;
+        Ok(())
+    }
+
+    /// Reads back the configuration currently in effect on this
+    /// RTCPeerConnection.
+    ///
+    /// Since `set_configuration` is the only way to change it, and every
+    /// call goes through `validate_mutation` first, the last value accepted
+    /// there is always what the native peer connection is holding.
+    pub fn get_configuration(&self) -> RTCConfiguration {
+        self.configuration.clone()
+    }
+
+    /// Creates an offer, optionally forcing an ICE restart.
+    ///
+    /// To fail over to a relay-only path after a network change, first call
+    /// `set_configuration` with `ice_transport_policy` set to `Relay` (and
+    /// fresh TURN `ice_servers` if they changed too), then create an offer
+    /// here with `ice_restart: true` and send it to the remote peer. The
+    /// ICE agent picks up the new policy and servers while generating a
+    /// fresh ufrag/pwd, so connectivity checks restart against the relay
+    /// path end to end.
+    pub fn create_offer(&self, options: RTCOfferOptions) -> Result<String> {
+        let sdp = unsafe { rtc_create_offer(self.raw, options.ice_restart) };
+        if sdp.is_null() {
+            return Err(anyhow::anyhow!("failed to create offer"));
+        }
+
+        let owned = unsafe { std::ffi::CStr::from_ptr(sdp) }
+            .to_string_lossy()
+            .into_owned();
+
+        // `sdp` is native-allocated; once we've copied it into an owned
+        // `String`, hand it back so the native side can release it.
+        unsafe {
+            rtc_free_string(sdp);
+        }
+
+        Ok(owned)
+    }
+
+    /// Triggers an ICE restart on an already-established connection,
+    /// equivalent to calling `create_offer` with `ice_restart: true` and
+    /// re-running the offer/answer exchange: the ICE agent generates a
+    /// fresh ufrag/pwd and restarts connectivity checks.
+    pub fn restart_ice(&self) {
+        unsafe {
+            rtc_restart_ice(self.raw);
+        }
+    }
+}
+
+#[cfg(test)]
+mod native_stub {
+    use libc::*;
+    use std::ffi::CString;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    pub static ICE_RESTARTED: AtomicBool = AtomicBool::new(false);
+
+    pub unsafe fn rtc_set_configuration(
+        _pc: *const c_void,
+        _config: *const crate::ffi::raw::rtc_peerconnection_configure::RawRTCPeerConnectionConfigure,
+    ) {
+    }
+
+    pub unsafe fn rtc_restart_ice(_pc: *const c_void) {
+        ICE_RESTARTED.store(true, Ordering::SeqCst);
+    }
+
+    pub unsafe fn rtc_create_offer(_pc: *const c_void, ice_restart: bool) -> *const c_char {
+        if ice_restart {
+            ICE_RESTARTED.store(true, Ordering::SeqCst);
+        }
+        CString::new("v=0\r\n").unwrap().into_raw()
+    }
+
+    pub unsafe fn rtc_free_string(s: *const c_char) {
+        let _ = CString::from_raw(s as *mut c_char);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::raw::rtc_peerconnection_configure::{
+        IceTransportPolicy, RTCConfigurationBuilder, RTCIceServerBuilder,
+    };
+    use std::sync::atomic::Ordering;
+
+    fn new_for_test() -> RTCPeerConnection {
+        RTCPeerConnection {
+            raw: std::ptr::null(),
+            configuration: RTCConfigurationBuilder::new().build().unwrap(),
+        }
+    }
+
+    #[test]
+    fn relay_failover_then_ice_restart() {
+        native_stub::ICE_RESTARTED.store(false, Ordering::SeqCst);
+        let mut pc = new_for_test();
+
+        let turn_server = RTCIceServerBuilder::new()
+            .url("turn:turn.example.com:3478")
+            .username("user")
+            .credential("pass")
+            .build()
+            .unwrap();
+
+        let relay_config = RTCConfigurationBuilder::new()
+            .ice_transport_policy(IceTransportPolicy::Relay)
+            .ice_server(turn_server)
+            .build()
+            .unwrap();
+
+        pc.set_configuration(relay_config).unwrap();
+        assert_eq!(
+            pc.get_configuration().ice_transport_policy,
+            Some(IceTransportPolicy::Relay)
+        );
+
+        let offer = pc
+            .create_offer(RTCOfferOptions { ice_restart: true })
+            .unwrap();
+        assert!(!offer.is_empty());
+        assert!(native_stub::ICE_RESTARTED.load(Ordering::SeqCst));
+
+        native_stub::ICE_RESTARTED.store(false, Ordering::SeqCst);
+        pc.restart_ice();
+        assert!(native_stub::ICE_RESTARTED.load(Ordering::SeqCst));
+    }
+}